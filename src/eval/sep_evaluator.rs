@@ -2,7 +2,7 @@ use jagua_rs::collision_detection::hazards::collector::HazardCollector;
 use crate::eval::sample_eval::{SampleEval, SampleEvaluator};
 use crate::eval::specialized_jaguars_pipeline::{collect_poly_collisions_in_detector_custom, SpecializedHazardCollector};
 use crate::quantify::tracker::CollisionTracker;
-use crate::symmetric::mirror_transformation;
+use crate::symmetric::{reflect_shape, symmetry_images, ReflectAxis, SymmetricConfig};
 use jagua_rs::entities::Item;
 use jagua_rs::entities::Layout;
 use jagua_rs::entities::PItemKey;
@@ -15,9 +15,18 @@ pub struct SeparationEvaluator<'a> {
     item: &'a Item,
     collector: SpecializedHazardCollector<'a>,
     shape_buff: SPolygon,
+    /// Scratch buffer for the vertical-mirror image, checked against `reflected_x_shape`.
     mirror_shape_buff: SPolygon,
+    /// Scratch buffer for the horizontal-mirror image, checked against `reflected_y_shape`.
+    horizontal_shape_buff: SPolygon,
+    /// Scratch buffer for the point (180°) image, checked against `item.shape_cd` directly.
+    point_shape_buff: SPolygon,
+    /// Chirality-correct x-mirror image of `item.shape_cd`.
+    reflected_x_shape: SPolygon,
+    /// Chirality-correct y-mirror image of `item.shape_cd`.
+    reflected_y_shape: SPolygon,
     n_evals: usize,
-    symmetric_axis_x: Option<f32>,
+    symmetric: Option<SymmetricConfig>,
 }
 
 impl<'a> SeparationEvaluator<'a> {
@@ -35,7 +44,7 @@ impl<'a> SeparationEvaluator<'a> {
         item: &'a Item,
         current_pk: PItemKey,
         ct: &'a CollisionTracker,
-        symmetric_axis_x: Option<f32>,
+        symmetric: Option<SymmetricConfig>,
     ) -> Self {
         let collector = SpecializedHazardCollector::new(layout, ct, current_pk);
 
@@ -45,8 +54,12 @@ impl<'a> SeparationEvaluator<'a> {
             collector,
             shape_buff: item.shape_cd.as_ref().clone(),
             mirror_shape_buff: item.shape_cd.as_ref().clone(),
+            horizontal_shape_buff: item.shape_cd.as_ref().clone(),
+            point_shape_buff: item.shape_cd.as_ref().clone(),
+            reflected_x_shape: reflect_shape(item.shape_cd.as_ref(), ReflectAxis::X),
+            reflected_y_shape: reflect_shape(item.shape_cd.as_ref(), ReflectAxis::Y),
             n_evals: 0,
-            symmetric_axis_x,
+            symmetric,
         }
     }
 }
@@ -54,7 +67,8 @@ impl<'a> SeparationEvaluator<'a> {
 impl<'a> SampleEvaluator for SeparationEvaluator<'a> {
     /// Evaluates a transformation. An upper bound can be provided to early terminate the process.
     /// Algorithm 7 from https://doi.org/10.48550/arXiv.2509.13329
-    /// In symmetric mode, also checks for collisions at the mirror position.
+    /// In symmetric mode, also checks for collisions at every image of the transform
+    /// under the container's symmetry group, summing their losses.
     fn evaluate_sample(&mut self, dt: DTransformation, upper_bound: Option<SampleEval>) -> SampleEval {
         self.n_evals += 1;
         let cde = self.layout.cde();
@@ -71,58 +85,56 @@ impl<'a> SampleEvaluator for SeparationEvaluator<'a> {
         //query the CDE, all colliding hazards will be stored in the detection map
         collect_poly_collisions_in_detector_custom(cde, &dt, &mut self.shape_buff, self.item.shape_cd.as_ref(), &mut self.collector);
 
-        let original_result = if self.collector.early_terminate(&self.shape_buff) {
-            SampleEval::Invalid
-        } else if self.collector.is_empty() {
-            SampleEval::Clear { loss: 0.0 }
+        if self.collector.early_terminate(&self.shape_buff) {
+            return SampleEval::Invalid;
+        }
+
+        let mut total_loss = if self.collector.is_empty() {
+            0.0
         } else {
-            SampleEval::Collision {
-                loss: self.collector.loss(&self.shape_buff),
-            }
+            self.collector.loss(&self.shape_buff)
         };
 
-        // In symmetric mode, also check the mirror position
-        if let Some(axis_x) = self.symmetric_axis_x {
-            match original_result {
-                SampleEval::Invalid => SampleEval::Invalid,
-                SampleEval::Clear { loss: orig_loss } | SampleEval::Collision { loss: orig_loss } => {
-                    // Compute mirror transformation
-                    let mirror_dt = mirror_transformation(dt, axis_x);
+        // In symmetric mode, also check every image under the symmetry group
+        if let Some(config) = self.symmetric {
+            for image in symmetry_images(dt, &config).into_iter().flatten() {
+                let remaining_bound = loss_bound - total_loss;
+                if remaining_bound <= 0.0 {
+                    return SampleEval::Collision { loss: total_loss };
+                }
+                self.collector.reload(remaining_bound);
 
-                    // Reload collector for mirror check
-                    let mirror_loss_bound = loss_bound - orig_loss;
-                    if mirror_loss_bound <= 0.0 {
-                        return SampleEval::Collision { loss: orig_loss };
-                    }
-                    self.collector.reload(mirror_loss_bound);
+                match image.reflect_axis {
+                    Some(ReflectAxis::X) => collect_poly_collisions_in_detector_custom(
+                        cde, &image.transformation, &mut self.mirror_shape_buff, &self.reflected_x_shape, &mut self.collector,
+                    ),
+                    Some(ReflectAxis::Y) => collect_poly_collisions_in_detector_custom(
+                        cde, &image.transformation, &mut self.horizontal_shape_buff, &self.reflected_y_shape, &mut self.collector,
+                    ),
+                    None => collect_poly_collisions_in_detector_custom(
+                        cde, &image.transformation, &mut self.point_shape_buff, self.item.shape_cd.as_ref(), &mut self.collector,
+                    ),
+                };
 
-                    // Check collisions at mirror position
-                    collect_poly_collisions_in_detector_custom(
-                        cde,
-                        &mirror_dt,
-                        &mut self.mirror_shape_buff,
-                        self.item.shape_cd.as_ref(),
-                        &mut self.collector
-                    );
+                let image_buff = match image.reflect_axis {
+                    Some(ReflectAxis::X) => &self.mirror_shape_buff,
+                    Some(ReflectAxis::Y) => &self.horizontal_shape_buff,
+                    None => &self.point_shape_buff,
+                };
 
-                    if self.collector.early_terminate(&self.mirror_shape_buff) {
-                        SampleEval::Invalid
-                    } else if self.collector.is_empty() {
-                        if orig_loss == 0.0 {
-                            SampleEval::Clear { loss: 0.0 }
-                        } else {
-                            SampleEval::Collision { loss: orig_loss }
-                        }
-                    } else {
-                        let mirror_loss = self.collector.loss(&self.mirror_shape_buff);
-                        SampleEval::Collision {
-                            loss: orig_loss + mirror_loss,
-                        }
-                    }
+                if self.collector.early_terminate(image_buff) {
+                    return SampleEval::Invalid;
+                }
+                if !self.collector.is_empty() {
+                    total_loss += self.collector.loss(image_buff);
                 }
             }
+        }
+
+        if total_loss == 0.0 {
+            SampleEval::Clear { loss: 0.0 }
         } else {
-            original_result
+            SampleEval::Collision { loss: total_loss }
         }
     }
 
@@ -130,4 +142,3 @@ impl<'a> SampleEvaluator for SeparationEvaluator<'a> {
         self.n_evals
     }
 }
-
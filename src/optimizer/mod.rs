@@ -2,48 +2,160 @@ use crate::config::*;
 use crate::optimizer::lbf::LBFBuilder;
 use crate::optimizer::separator::Separator;
 use jagua_rs::probs::spp::entities::{SPInstance, SPSolution};
+use jagua_rs::probs::spp::io::ext_repr::{ExtSPInstance, ExtSPSolution};
 use rand::{RngCore, SeedableRng};
-use std::time::Duration;
-use log::info;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+use log::{info, warn};
 use rand_xoshiro::Xoshiro256PlusPlus;
 use crate::consts::LBF_SAMPLE_CONFIG;
 use crate::optimizer::compress::compression_phase;
 use crate::optimizer::explore::exploration_phase;
+use crate::symmetric::{SymmetricConfig, SymmetryKind};
+use crate::util::io::{write_checkpoint, ExtSPOutput};
 use crate::util::listener::{ReportType, SolutionListener};
 use crate::util::terminator::Terminator;
 
+/// Wraps a [`SolutionListener`], additionally snapshotting a reported
+/// solution to disk so long-running optimizations can be resumed from the
+/// checkpoint after a crash or a time limit (see `read_spp_input`). A report
+/// is snapshotted only if at least the configured interval has passed since
+/// the previous snapshot (or since construction); nothing is written between
+/// reports, and a report that arrives sooner is not written at all.
+/// Disabled entirely when `checkpoint` is `None`.
+struct CheckpointingListener<'a, L: SolutionListener> {
+    inner: &'a mut L,
+    checkpoint: Option<(PathBuf, Duration)>,
+    last_checkpoint: Instant,
+}
+
+impl<'a, L: SolutionListener> CheckpointingListener<'a, L> {
+    fn new(inner: &'a mut L, checkpoint: Option<(PathBuf, Duration)>) -> Self {
+        Self {
+            inner,
+            checkpoint,
+            last_checkpoint: Instant::now(),
+        }
+    }
+}
+
+impl<'a, L: SolutionListener> SolutionListener for CheckpointingListener<'a, L> {
+    fn report(&mut self, report_type: ReportType, solution: &SPSolution, instance: &SPInstance) {
+        self.inner.report(report_type, solution, instance);
+
+        if let Some((path, interval)) = &self.checkpoint {
+            if self.last_checkpoint.elapsed() >= *interval {
+                let output = ExtSPOutput {
+                    instance: ExtSPInstance::from(instance),
+                    solution: ExtSPSolution::from(solution),
+                };
+                if let Err(e) = write_checkpoint(&output, path) {
+                    warn!("[OPT] failed to write checkpoint to {}: {e}", path.display());
+                }
+                self.last_checkpoint = Instant::now();
+            }
+        }
+    }
+}
+
 pub mod lbf;
 pub mod separator;
 mod worker;
 pub mod explore;
 pub mod compress;
 
+/// Forwards a portfolio worker's reports to the caller's real listener
+/// (`inner`, shared across all workers behind a lock), but only those whose
+/// objective is at least as good as the best known across the whole portfolio
+/// (tracked via the shared `best_width`). This keeps every consumer of
+/// `SolutionListener` meaningful during the exploration phase even with
+/// `--workers > 1` — not just checkpointing (handled by `inner` itself, since
+/// it is the caller's `CheckpointingListener`), but also anything else a
+/// listener might drive, e.g. live progress output — without concurrent
+/// workers trampling each other's reports or non-improving workers flooding
+/// the listener with solutions that never became the portfolio's best.
+struct PortfolioListener<'a, 'b, L: SolutionListener> {
+    inner: Arc<Mutex<&'b mut CheckpointingListener<'a, L>>>,
+    best_width: Arc<Mutex<f32>>,
+}
+
+impl<'a, 'b, L: SolutionListener> PortfolioListener<'a, 'b, L> {
+    fn new(inner: Arc<Mutex<&'b mut CheckpointingListener<'a, L>>>, best_width: Arc<Mutex<f32>>) -> Self {
+        Self { inner, best_width }
+    }
+}
+
+impl<'a, 'b, L: SolutionListener> SolutionListener for PortfolioListener<'a, 'b, L> {
+    fn report(&mut self, report_type: ReportType, solution: &SPSolution, instance: &SPInstance) {
+        if !claims_best(&self.best_width, solution.strip_width()) {
+            return; // a tighter solution was already reported (by this or another worker)
+        }
+        self.inner.lock().unwrap().report(report_type, solution, instance);
+    }
+}
+
+/// Atomically checks whether `width` is at least as good as the current best
+/// tracked in `best_width`, and if so, updates it and returns `true`. Split
+/// out of `PortfolioListener::report` so this (the actual concurrency-
+/// sensitive part of the portfolio checkpointing/reporting gate) can be unit
+/// tested without needing a real `SolutionListener`/`SPSolution`.
+fn claims_best(best_width: &Mutex<f32>, width: f32) -> bool {
+    let mut best_width = best_width.lock().unwrap();
+    if width > *best_width {
+        return false;
+    }
+    *best_width = width;
+    true
+}
+
+/// Picks the entry with the smallest `key`. Strip-packing exploration is
+/// seed-sensitive, so a portfolio's workers may each converge to a different
+/// stable width; keep the tightest one.
+fn min_by_key_f32<T>(items: Vec<T>, key: impl Fn(&T) -> f32) -> Option<T> {
+    items.into_iter().min_by(|a, b| key(a).partial_cmp(&key(b)).unwrap())
+}
+
 ///Algorithm 11 from https://doi.org/10.48550/arXiv.2509.13329
 pub fn optimize(
     instance: SPInstance,
     rng: Xoshiro256PlusPlus,
-    sol_listener: &mut impl SolutionListener,
-    terminator: &mut impl Terminator,
+    sol_listener: &mut (impl SolutionListener + Send),
+    terminator: &mut (impl Terminator + Clone + Send),
     expl_config: &ExplorationConfig,
     cmpr_config: &CompressionConfig,
     initial_solution: Option<&SPSolution>
 ) -> SPSolution {
-    optimize_with_symmetric(instance, rng, sol_listener, terminator, expl_config, cmpr_config, initial_solution, false)
+    optimize_with_symmetric(instance, rng, sol_listener, terminator, expl_config, cmpr_config, initial_solution, None, None, 1)
 }
 
-/// Optimize with optional symmetric mode.
-/// In symmetric mode, items are placed only in the left half of the container,
-/// and collision checking considers mirror positions.
+/// Optimize with an optional symmetry group, periodic checkpointing, and a
+/// multi-seed exploration portfolio.
+/// In symmetric mode, items are placed only in one fundamental domain of the
+/// container, and collision checking considers every image under the group.
+/// When `checkpoint` is `Some((path, interval))`, a reported solution is
+/// atomically snapshotted to `path` when at least `interval` has passed since
+/// the previous snapshot; no snapshot is written between reports.
+/// When `workers > 1`, exploration runs `workers` independent, differently
+/// seeded separators concurrently (each bound to its own `Terminator`
+/// timeout slice), and the best exploration solution by objective feeds the
+/// single compression phase that follows.
 pub fn optimize_with_symmetric(
     instance: SPInstance,
     mut rng: Xoshiro256PlusPlus,
-    sol_listener: &mut impl SolutionListener,
-    terminator: &mut impl Terminator,
+    sol_listener: &mut (impl SolutionListener + Send),
+    terminator: &mut (impl Terminator + Clone + Send),
     expl_config: &ExplorationConfig,
     cmpr_config: &CompressionConfig,
     initial_solution: Option<&SPSolution>,
-    symmetric: bool,
+    symmetric: Option<SymmetryKind>,
+    checkpoint: Option<(PathBuf, Duration)>,
+    workers: usize,
 ) -> SPSolution {
+    let mut sol_listener = CheckpointingListener::new(sol_listener, checkpoint);
+    let sol_listener = &mut sol_listener;
+
     let mut next_rng = || Xoshiro256PlusPlus::seed_from_u64(rng.next_u64());
     let start_prob = match initial_solution {
         None => {
@@ -58,33 +170,89 @@ pub fn optimize_with_symmetric(
         }
     };
 
-    // Calculate symmetric axis if in symmetric mode
-    let symmetric_axis_x = if symmetric {
-        Some(start_prob.strip_width() / 2.0)
-    } else {
-        None
-    };
+    // Build the symmetric configuration, if any: every kind but a single
+    // vertical axis also needs a horizontal axis through the strip's center.
+    let symmetric_config = symmetric.map(|kind| {
+        let axis_y = match kind {
+            SymmetryKind::VerticalAxis => None,
+            _ => Some(start_prob.strip_height() / 2.0),
+        };
+        SymmetricConfig::new(start_prob.strip_width(), kind, axis_y)
+    });
 
-    if symmetric {
-        info!("[OPT] symmetric mode enabled, axis at {:.3}", symmetric_axis_x.unwrap());
+    if let Some(config) = &symmetric_config {
+        info!(
+            "[OPT] symmetric mode enabled ({:?}), axis at x={:.3}{}",
+            config.kind,
+            config.axis_x,
+            config.axis_y.map(|y| format!(", y={y:.3}")).unwrap_or_default()
+        );
     }
 
     terminator.new_timeout(expl_config.time_limit);
-    let mut expl_separator = Separator::new_with_symmetric(
-        instance.clone(), start_prob, next_rng(), expl_config.separator_config, symmetric_axis_x
-    );
-    let solutions = exploration_phase(
-        &instance,
-        &mut expl_separator,
-        sol_listener,
-        terminator,
-        expl_config,
-    );
-    let final_explore_sol = solutions.last().unwrap().clone();
+
+    let (final_explore_sol, expl_prob) = if workers <= 1 {
+        let mut expl_separator = Separator::new_with_symmetric(
+            instance.clone(), start_prob, next_rng(), expl_config.separator_config, symmetric_config
+        );
+        let solutions = exploration_phase(
+            &instance,
+            &mut expl_separator,
+            sol_listener,
+            terminator,
+            expl_config,
+        );
+        let sol = solutions.last().unwrap().clone();
+        (sol, expl_separator.prob)
+    } else {
+        info!("[OPT] exploring with a portfolio of {workers} workers");
+        let worker_seeds: Vec<_> = (0..workers).map(|_| next_rng()).collect();
+        let best_width = Arc::new(Mutex::new(f32::INFINITY));
+        // Reborrowed rather than moved, so `sol_listener` is usable again
+        // (for the compression phase and the final report) once this `Arc`
+        // is dropped at the end of the block and the borrow it holds ends.
+        let shared_listener = Arc::new(Mutex::new(&mut *sol_listener));
+
+        let outcomes: Vec<(SPSolution, _)> = thread::scope(|scope| {
+            let handles: Vec<_> = worker_seeds.into_iter().enumerate().map(|(i, worker_seed)| {
+                let worker_instance = instance.clone();
+                let worker_prob = start_prob.clone();
+                let mut worker_terminator = terminator.clone();
+                worker_terminator.new_timeout(expl_config.time_limit);
+                let mut worker_listener = PortfolioListener::new(
+                    Arc::clone(&shared_listener), Arc::clone(&best_width)
+                );
+
+                thread::Builder::new()
+                    .name(format!("worker-{i}"))
+                    .spawn_scoped(scope, move || {
+                        let mut worker_separator = Separator::new_with_symmetric(
+                            worker_instance.clone(), worker_prob, worker_seed, expl_config.separator_config, symmetric_config
+                        );
+                        let solutions = exploration_phase(
+                            &worker_instance,
+                            &mut worker_separator,
+                            &mut worker_listener,
+                            &mut worker_terminator,
+                            expl_config,
+                        );
+                        let sol = solutions.last().unwrap().clone();
+                        (sol, worker_separator.prob)
+                    })
+                    .expect("failed to spawn exploration worker")
+            }).collect();
+
+            handles.into_iter().map(|h| h.join().expect("exploration worker panicked")).collect()
+        });
+        drop(shared_listener);
+
+        min_by_key_f32(outcomes, |(_, prob)| prob.strip_width())
+            .expect("at least one exploration worker to report a result")
+    };
 
     terminator.new_timeout(cmpr_config.time_limit);
     let mut cmpr_separator = Separator::new_with_symmetric(
-        expl_separator.instance, expl_separator.prob, next_rng(), cmpr_config.separator_config, symmetric_axis_x
+        instance.clone(), expl_prob, next_rng(), cmpr_config.separator_config, symmetric_config
     );
     let cmpr_sol = compression_phase(
         &instance,
@@ -98,4 +266,50 @@ pub fn optimize_with_symmetric(
     sol_listener.report(ReportType::Final, &cmpr_sol, &instance);
 
     cmpr_sol
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_claims_best_rejects_a_worse_width() {
+        let best_width = Mutex::new(5.0);
+        assert!(!claims_best(&best_width, 6.0));
+        assert_eq!(*best_width.lock().unwrap(), 5.0);
+    }
+
+    #[test]
+    fn test_claims_best_accepts_an_equal_or_better_width() {
+        let best_width = Mutex::new(5.0);
+        assert!(claims_best(&best_width, 5.0));
+        assert!(claims_best(&best_width, 3.0));
+        assert_eq!(*best_width.lock().unwrap(), 3.0);
+    }
+
+    #[test]
+    fn test_claims_best_is_consistent_across_concurrent_updates() {
+        let best_width = Arc::new(Mutex::new(f32::INFINITY));
+        thread::scope(|scope| {
+            for width in [4.0, 2.0, 3.0, 1.0, 5.0] {
+                let best_width = Arc::clone(&best_width);
+                scope.spawn(move || {
+                    claims_best(&best_width, width);
+                });
+            }
+        });
+        assert_eq!(*best_width.lock().unwrap(), 1.0);
+    }
+
+    #[test]
+    fn test_min_by_key_f32_picks_the_smallest() {
+        let items = vec![("c", 3.0), ("a", 1.0), ("b", 2.0)];
+        assert_eq!(min_by_key_f32(items, |(_, w)| *w), Some(("a", 1.0)));
+    }
+
+    #[test]
+    fn test_min_by_key_f32_empty_is_none() {
+        let items: Vec<(&str, f32)> = vec![];
+        assert_eq!(min_by_key_f32(items, |(_, w)| *w), None);
+    }
 }
\ No newline at end of file
@@ -1,33 +1,85 @@
 //! Symmetric mode handling for sparrow.
 //!
-//! In symmetric mode, items are placed only in the left half of the container,
-//! and their mirror positions on the right half are automatically considered
-//! for collision detection.
+//! In symmetric mode, items are placed only in one fundamental domain of the
+//! container, and their images under the container's symmetry group are
+//! automatically considered for collision detection. Because most of these
+//! images are true reflections (chirality flips) rather than rotations, we
+//! pair a reflected copy of the item's polygon ([`reflect_shape`]) with a
+//! matching rigid transform ([`symmetry_images`]) rather than approximating
+//! a reflection as a rotation.
 
 use jagua_rs::geometry::{DTransformation, Transformation};
-use jagua_rs::geometry::primitives::Rect;
+use jagua_rs::geometry::primitives::{Point, Rect, SPolygon};
 use std::f32::consts::PI;
+use std::str::FromStr;
 
-/// Holds configuration for symmetric packing mode
+/// The symmetry group a container is packed under.
+///
+/// `VerticalAxis` and `HorizontalAxis` mirror across a single line; `Point`
+/// rotates 180° about the axis intersection; `Both` is the dihedral group
+/// generated by the two mirrors (and therefore also contains the point
+/// image), giving up to three non-trivial images per placement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymmetryKind {
+    VerticalAxis,
+    HorizontalAxis,
+    Point,
+    Both,
+}
+
+impl FromStr for SymmetryKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "vertical" | "v" => Ok(Self::VerticalAxis),
+            "horizontal" | "h" => Ok(Self::HorizontalAxis),
+            "point" | "p" => Ok(Self::Point),
+            "both" | "dihedral" | "d" => Ok(Self::Both),
+            other => Err(format!(
+                "unknown symmetry kind '{other}', expected one of: vertical, horizontal, point, both"
+            )),
+        }
+    }
+}
+
+/// Which axis (if any) an image's reflected shape was built from, so the
+/// caller knows which precomputed polygon buffer to query collisions against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReflectAxis {
+    X,
+    Y,
+}
+
+/// Holds configuration for symmetric packing mode.
 #[derive(Debug, Clone, Copy)]
 pub struct SymmetricConfig {
-    /// The x-coordinate of the symmetry axis (usually strip_width / 2)
+    /// The x-coordinate of the vertical symmetry axis (usually strip_width / 2)
     pub axis_x: f32,
+    /// The y-coordinate of the horizontal symmetry axis, required by every
+    /// `kind` other than `VerticalAxis`
+    pub axis_y: Option<f32>,
+    /// Which symmetry group the container is packed under
+    pub kind: SymmetryKind,
     /// Whether symmetric mode is enabled
     pub enabled: bool,
 }
 
 impl SymmetricConfig {
-    pub fn new(strip_width: f32, enabled: bool) -> Self {
+    pub fn new(strip_width: f32, kind: SymmetryKind, axis_y: Option<f32>) -> Self {
         Self {
             axis_x: strip_width / 2.0,
-            enabled,
+            axis_y,
+            kind,
+            enabled: true,
         }
     }
 
     pub fn disabled() -> Self {
         Self {
             axis_x: 0.0,
+            axis_y: None,
+            kind: SymmetryKind::VerticalAxis,
             enabled: false,
         }
     }
@@ -38,40 +90,138 @@ impl SymmetricConfig {
     }
 }
 
-/// Compute the mirror transformation of a given transformation around the symmetry axis.
+/// Build a chirality-correct mirror image of an item's reference polygon.
+///
+/// A `DTransformation` can only rotate and translate, so it cannot by itself
+/// reproduce a true reflection for shapes that aren't already symmetric
+/// about `axis`. This negates the local coordinate of every vertex along
+/// `axis` (about the shape's own reference frame, i.e. the frame
+/// `item.shape_cd` is already defined in) and reverses the vertex order so
+/// outward normals stay consistent. Pair the result with the matching image
+/// transform from [`symmetry_images`] to place it at the true mirror position.
+pub fn reflect_shape(shape: &SPolygon, axis: ReflectAxis) -> SPolygon {
+    let points: Vec<Point> = shape
+        .points()
+        .iter()
+        .rev()
+        .map(|p| match axis {
+            ReflectAxis::X => Point::new(-p.x(), p.y()),
+            ReflectAxis::Y => Point::new(p.x(), -p.y()),
+        })
+        .collect();
+    SPolygon::new(points)
+}
+
+/// One non-trivial image of a placement under the container's symmetry group.
+#[derive(Debug, Clone, Copy)]
+pub struct SymmetryImage {
+    /// The transformation that places the associated reflected (or, for
+    /// `reflect_axis: None`, original) shape at the image's position
+    pub transformation: DTransformation,
+    /// Which [`reflect_shape`] buffer this image must be checked against;
+    /// `None` means the original, unreflected shape (used for the point image,
+    /// since a 180° rotation doesn't change chirality)
+    pub reflect_axis: Option<ReflectAxis>,
+}
+
+/// Compute every non-trivial image transform of `dt` required by `config.kind`.
+///
+/// - Vertical mirror: `(x, y, r) -> (2*axis_x - x, y, -r)`, against the
+///   x-reflected shape.
+/// - Horizontal mirror: `(x, y, r) -> (x, 2*axis_y - y, -r)`, against the
+///   y-reflected shape.
+/// - Point (180°) image: `(x, y, r) -> (2*axis_x - x, 2*axis_y - y, r + PI)`,
+///   against the original shape (a half turn preserves chirality).
+/// - `Both` (dihedral): all three of the above.
+///
+/// `config.axis_y` must be `Some` for every kind other than `VerticalAxis`.
 ///
-/// For a point at (x, y) with rotation r, its mirror around axis_x is:
-/// - x' = 2 * axis_x - x
-/// - y' = y (unchanged)
-/// - r' = PI - r (mirror the rotation)
-pub fn mirror_transformation(dt: DTransformation, axis_x: f32) -> DTransformation {
+/// Returns a fixed-size array rather than a `Vec`: this is called once per
+/// sampled transform inside `SeparationEvaluator::evaluate_sample`, the hot
+/// inner loop of the search, which preallocates its scratch shape buffers for
+/// exactly this reason. Unused slots are `None`; iterate with
+/// `.into_iter().flatten()`.
+pub fn symmetry_images(dt: DTransformation, config: &SymmetricConfig) -> [Option<SymmetryImage>; 3] {
     let (x, y) = dt.translation();
     let r = dt.rotation();
+    let axis_x = config.axis_x;
 
-    // Mirror x coordinate
-    let mirror_x = 2.0 * axis_x - x;
+    let vertical = || SymmetryImage {
+        transformation: DTransformation::new(-r, (2.0 * axis_x - x, y)),
+        reflect_axis: Some(ReflectAxis::X),
+    };
+    let horizontal = |axis_y: f32| SymmetryImage {
+        transformation: DTransformation::new(-r, (x, 2.0 * axis_y - y)),
+        reflect_axis: Some(ReflectAxis::Y),
+    };
+    let point = |axis_y: f32| SymmetryImage {
+        transformation: DTransformation::new(r + PI, (2.0 * axis_x - x, 2.0 * axis_y - y)),
+        reflect_axis: None,
+    };
 
-    // Mirror rotation: flip around vertical axis
-    // If original rotation is r, mirror is PI - r (or equivalently -r with a flip)
-    let mirror_r = PI - r;
-
-    DTransformation::new(mirror_r, (mirror_x, y))
+    match config.kind {
+        SymmetryKind::VerticalAxis => [Some(vertical()), None, None],
+        SymmetryKind::HorizontalAxis => {
+            let axis_y = config.axis_y.expect("horizontal symmetry requires axis_y");
+            [Some(horizontal(axis_y)), None, None]
+        }
+        SymmetryKind::Point => {
+            let axis_y = config.axis_y.expect("point symmetry requires axis_y");
+            [Some(point(axis_y)), None, None]
+        }
+        SymmetryKind::Both => {
+            let axis_y = config.axis_y.expect("dihedral symmetry requires axis_y");
+            [Some(vertical()), Some(horizontal(axis_y)), Some(point(axis_y))]
+        }
+    }
 }
 
 /// Get the valid sampling bounding box for symmetric mode.
-/// In symmetric mode, we only sample from the left half of the container.
-pub fn get_symmetric_sample_bbox(container_bbox: Rect, axis_x: f32) -> Option<Rect> {
+///
+/// `VerticalAxis` and `HorizontalAxis` are two-fold groups (identity + one
+/// mirror image), so their fundamental domain is a *half* of the container:
+/// restricting both axes for them would leave two quarters of the container
+/// permanently unreachable by sampling or by any image. `Point` is likewise
+/// two-fold (identity + one 180° image): restricting `x` alone is enough,
+/// since that single image already maps the left half onto the right half.
+/// Only `Both`, the four-fold dihedral group, needs both axes restricted.
+pub fn get_symmetric_sample_bbox(container_bbox: Rect, config: &SymmetricConfig) -> Option<Rect> {
+    let x_max = match config.kind {
+        SymmetryKind::HorizontalAxis => container_bbox.x_max,
+        _ => config.axis_x,
+    };
+    let y_max = match config.kind {
+        SymmetryKind::VerticalAxis | SymmetryKind::Point => container_bbox.y_max,
+        SymmetryKind::HorizontalAxis | SymmetryKind::Both => {
+            config.axis_y.unwrap_or(container_bbox.y_max)
+        }
+    };
     Rect::try_new(
         container_bbox.x_min,
         container_bbox.y_min,
-        axis_x,  // Only sample up to the axis
-        container_bbox.y_max,
+        x_max,
+        y_max,
     ).ok()
 }
 
-/// Check if a transformation is in the valid region for symmetric mode (left half).
-pub fn is_in_valid_region(dt: DTransformation, axis_x: f32) -> bool {
-    dt.translation().0 <= axis_x
+/// Check if a transformation is in the valid region for symmetric mode
+/// (the fundamental domain of the container's symmetry group).
+///
+/// See [`get_symmetric_sample_bbox`] for why only `Both` restricts both axes.
+pub fn is_in_valid_region(dt: DTransformation, config: &SymmetricConfig) -> bool {
+    let (x, y) = dt.translation();
+    match config.kind {
+        SymmetryKind::VerticalAxis => x <= config.axis_x,
+        SymmetryKind::HorizontalAxis => {
+            let axis_y = config.axis_y.expect("horizontal symmetry requires axis_y");
+            y <= axis_y
+        }
+        SymmetryKind::Point => x <= config.axis_x,
+        SymmetryKind::Both => {
+            let axis_y = config.axis_y.expect("dihedral symmetry requires axis_y");
+            x <= config.axis_x && y <= axis_y
+        }
+    }
 }
 
 #[cfg(test)]
@@ -79,17 +229,124 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_mirror_transformation() {
+    fn test_symmetry_images_vertical() {
         let dt = DTransformation::new(0.0, (1.0, 2.0));
-        let axis_x = 5.0;
-
-        let mirrored = mirror_transformation(dt, axis_x);
+        let config = SymmetricConfig::new(10.0, SymmetryKind::VerticalAxis, None);
 
+        let images: Vec<_> = symmetry_images(dt, &config).into_iter().flatten().collect();
+        assert_eq!(images.len(), 1);
+        assert_eq!(images[0].reflect_axis, Some(ReflectAxis::X));
         // x should be mirrored: 2 * 5.0 - 1.0 = 9.0
-        assert!((mirrored.translation().0 - 9.0).abs() < 1e-6);
-        // y should be unchanged
-        assert!((mirrored.translation().1 - 2.0).abs() < 1e-6);
-        // rotation should be PI - 0 = PI
-        assert!((mirrored.rotation() - PI).abs() < 1e-6);
+        assert!((images[0].transformation.translation().0 - 9.0).abs() < 1e-6);
+        assert!((images[0].transformation.translation().1 - 2.0).abs() < 1e-6);
+        // rotation should be -r = 0.0 (the reflected shape carries the flip now)
+        assert!((images[0].transformation.rotation() - 0.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_symmetry_images_point() {
+        let dt = DTransformation::new(0.0, (1.0, 2.0));
+        let mut config = SymmetricConfig::new(10.0, SymmetryKind::Point, Some(3.0));
+        config.axis_y = Some(3.0);
+
+        let images: Vec<_> = symmetry_images(dt, &config).into_iter().flatten().collect();
+        assert_eq!(images.len(), 1);
+        assert_eq!(images[0].reflect_axis, None);
+        assert!((images[0].transformation.translation().0 - 9.0).abs() < 1e-6);
+        assert!((images[0].transformation.translation().1 - 4.0).abs() < 1e-6);
+        assert!((images[0].transformation.rotation() - PI).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_symmetry_images_both_yields_three() {
+        let dt = DTransformation::new(0.0, (1.0, 2.0));
+        let config = SymmetricConfig::new(10.0, SymmetryKind::Both, Some(3.0));
+
+        let images: Vec<_> = symmetry_images(dt, &config).into_iter().flatten().collect();
+        assert_eq!(images.len(), 3);
+    }
+
+    #[test]
+    fn test_reflect_shape() {
+        let shape = SPolygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 1.0),
+            Point::new(0.0, 1.0),
+        ]);
+
+        let reflected = reflect_shape(&shape, ReflectAxis::X);
+        let points: Vec<Point> = reflected.points().to_vec();
+
+        // x-coordinates are negated about the shape's local x = 0
+        assert_eq!(points, vec![
+            Point::new(0.0, 1.0),
+            Point::new(-2.0, 1.0),
+            Point::new(-2.0, 0.0),
+            Point::new(0.0, 0.0),
+        ]);
+    }
+
+    #[test]
+    fn test_symmetry_kind_from_str() {
+        assert_eq!(SymmetryKind::from_str("point").unwrap(), SymmetryKind::Point);
+        assert_eq!(SymmetryKind::from_str("DIHEDRAL").unwrap(), SymmetryKind::Both);
+        assert!(SymmetryKind::from_str("diagonal").is_err());
+    }
+
+    fn container_bbox() -> Rect {
+        Rect::try_new(0.0, 0.0, 10.0, 6.0).unwrap()
+    }
+
+    #[test]
+    fn test_sample_bbox_and_valid_region_vertical_axis_is_a_half() {
+        let config = SymmetricConfig::new(10.0, SymmetryKind::VerticalAxis, None);
+        let bbox = get_symmetric_sample_bbox(container_bbox(), &config).unwrap();
+
+        // full height, left half only
+        assert_eq!(bbox.x_max, 5.0);
+        assert_eq!(bbox.y_max, 6.0);
+
+        assert!(is_in_valid_region(DTransformation::new(0.0, (1.0, 5.9)), &config));
+        assert!(!is_in_valid_region(DTransformation::new(0.0, (9.0, 0.0)), &config));
+    }
+
+    #[test]
+    fn test_sample_bbox_and_valid_region_horizontal_axis_is_a_half() {
+        let config = SymmetricConfig::new(10.0, SymmetryKind::HorizontalAxis, Some(3.0));
+        let bbox = get_symmetric_sample_bbox(container_bbox(), &config).unwrap();
+
+        // full width, bottom half only
+        assert_eq!(bbox.x_max, 10.0);
+        assert_eq!(bbox.y_max, 3.0);
+
+        assert!(is_in_valid_region(DTransformation::new(0.0, (9.9, 1.0)), &config));
+        assert!(!is_in_valid_region(DTransformation::new(0.0, (0.0, 5.9)), &config));
+    }
+
+    #[test]
+    fn test_sample_bbox_and_valid_region_point_is_a_half() {
+        let config = SymmetricConfig::new(10.0, SymmetryKind::Point, Some(3.0));
+        let bbox = get_symmetric_sample_bbox(container_bbox(), &config).unwrap();
+
+        // left half only, full height
+        assert_eq!(bbox.x_max, 5.0);
+        assert_eq!(bbox.y_max, 6.0);
+
+        assert!(is_in_valid_region(DTransformation::new(0.0, (1.0, 5.9)), &config));
+        assert!(!is_in_valid_region(DTransformation::new(0.0, (9.0, 0.0)), &config));
+    }
+
+    #[test]
+    fn test_sample_bbox_and_valid_region_both_is_a_quadrant() {
+        let config = SymmetricConfig::new(10.0, SymmetryKind::Both, Some(3.0));
+        let bbox = get_symmetric_sample_bbox(container_bbox(), &config).unwrap();
+
+        assert_eq!(bbox.x_max, 5.0);
+        assert_eq!(bbox.y_max, 3.0);
+
+        assert!(is_in_valid_region(DTransformation::new(0.0, (1.0, 1.0)), &config));
+        assert!(!is_in_valid_region(DTransformation::new(0.0, (9.0, 1.0)), &config));
+        assert!(!is_in_valid_region(DTransformation::new(0.0, (1.0, 5.9)), &config));
     }
 }
@@ -8,6 +8,7 @@ use svg::Document;
 use anyhow::{Context, Result};
 use clap::Parser;
 use jagua_rs::probs::spp::io::ext_repr::{ExtSPInstance, ExtSPSolution};
+use crate::symmetric::SymmetryKind;
 use crate::EPOCH;
 
 #[derive(Parser)]
@@ -36,8 +37,25 @@ pub struct MainCli {
     pub rng_seed: Option<u64>,
 
     /// Enable symmetric mode (for even number of items)
-    #[arg(long, help = "Enable symmetric packing mode - items are mirrored around center")]
-    pub symmetric: bool,
+    #[arg(
+        long,
+        num_args = 0..=1,
+        default_missing_value = "vertical",
+        help = "Enable symmetric packing mode: vertical (default), horizontal, point, or both (dihedral), e.g. --symmetric=point"
+    )]
+    pub symmetric: Option<SymmetryKind>,
+
+    /// Snapshot a reported solution to disk at most once per interval
+    #[arg(long, requires = "checkpoint_path", help = "Write a checkpoint on a reported solution at most every N seconds (requires --checkpoint-path)")]
+    pub checkpoint_interval: Option<u64>,
+
+    /// Where to write periodic checkpoints
+    #[arg(long, help = "Path to write periodic checkpoints to, in the same format as -i")]
+    pub checkpoint_path: Option<String>,
+
+    /// Run this many independent exploration separators concurrently and keep the best
+    #[arg(long, default_value_t = 1, help = "Explore with N independent seeded separators in parallel, then compress the best one")]
+    pub workers: usize,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -115,6 +133,46 @@ pub fn write_json(json: &impl Serialize, path: &Path, log_lvl: Level) -> Result<
     Ok(())
 }
 
+/// Write `json` to `path` atomically: serialize to a `.tmp` sibling file,
+/// then rename it into place. A process killed mid-write leaves at most a
+/// stale `.tmp` file behind and never a corrupt `path`; the next successful
+/// write always overwrites that stale `.tmp` before it could ever be read.
+fn write_json_atomic(json: &impl Serialize, path: &Path) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        if !parent.as_os_str().is_empty() {
+            fs::create_dir_all(parent).context("could not create parent directory")?;
+        }
+    }
+    let tmp_file_name = format!(
+        "{}.tmp",
+        path.file_name().and_then(|n| n.to_str()).unwrap_or("checkpoint.json")
+    );
+    let tmp_path = path.with_file_name(tmp_file_name);
+
+    let file = File::create(&tmp_path).context("could not create temporary file")?;
+    serde_json::to_writer_pretty(file, json).context("could not serialize")?;
+    fs::rename(&tmp_path, path).context("could not move file into place")?;
+    Ok(())
+}
+
+/// Atomically snapshot the current best solution to `path`.
+///
+/// Because [`read_spp_input`] already falls through to warm-starting when
+/// the input parses as a full [`ExtSPOutput`], a crashed or time-limited run
+/// can be resumed simply by pointing `-i` at the checkpoint file.
+pub fn write_checkpoint(output: &ExtSPOutput, path: &Path) -> Result<()> {
+    write_json_atomic(output, path)?;
+    log!(
+        Level::Debug,
+        "[OPT] checkpoint written to file://{}",
+        fs::canonicalize(path)
+            .expect("could not canonicalize path")
+            .to_str()
+            .unwrap()
+    );
+    Ok(())
+}
+
 pub fn read_spp_input(path: &Path) -> Result<(ExtSPInstance, Option<ExtSPSolution>)> {
     let input_str = fs::read_to_string(path).context("could not read input file")?;
     //try parsing a full output (instance + solution)
@@ -130,3 +188,50 @@ pub fn read_spp_input(path: &Path) -> Result<(ExtSPInstance, Option<ExtSPSolutio
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Dummy {
+        value: u32,
+    }
+
+    #[test]
+    fn test_write_json_atomic_round_trips_and_cleans_up_tmp() {
+        let dir = std::env::temp_dir().join("sparrow_io_test_round_trip");
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("checkpoint.json");
+
+        write_json_atomic(&Dummy { value: 42 }, &path).unwrap();
+
+        let read_back: Dummy = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(read_back, Dummy { value: 42 });
+        assert!(!path.with_file_name("checkpoint.json.tmp").exists());
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_write_json_atomic_overwrites_stale_tmp_and_prior_checkpoint() {
+        let dir = std::env::temp_dir().join("sparrow_io_test_stale_tmp");
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("checkpoint.json");
+        let tmp_path = path.with_file_name("checkpoint.json.tmp");
+
+        // a stale .tmp left behind by a process killed mid-write...
+        fs::write(&tmp_path, "garbage from a killed process").unwrap();
+        // ...alongside a valid checkpoint from an earlier successful run
+        write_json_atomic(&Dummy { value: 1 }, &path).unwrap();
+
+        // a fresh write should clobber both the stale .tmp and the old checkpoint
+        write_json_atomic(&Dummy { value: 2 }, &path).unwrap();
+
+        let read_back: Dummy = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(read_back, Dummy { value: 2 });
+        assert!(!tmp_path.exists());
+
+        fs::remove_dir_all(&dir).ok();
+    }
+}